@@ -0,0 +1,92 @@
+//! Array destructuring assignment pattern node.
+
+use super::Node;
+use crate::syntax::{lexer::Error as LexError, parser::ParseError};
+use std::fmt;
+
+/// A single position inside an `ArrayPattern`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArrayPatternElement {
+    /// An elided (skipped) position, e.g. the gap in `[a, , b] = arr`.
+    Elision,
+    /// A binding target, which may itself be a nested pattern, optionally
+    /// carrying a default initializer (`a = 1`) folded into the node by the
+    /// ordinary assignment-expression parse of the element.
+    Pattern(Node),
+    /// A trailing rest element: `...rest`. Only valid in the final position.
+    Rest(Box<Node>),
+}
+
+/// An array destructuring assignment target: `[a, b = 1, ...rest] = arr`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArrayPattern {
+    bindings: Box<[ArrayPatternElement]>,
+}
+
+impl ArrayPattern {
+    /// Reinterprets the elements of an already-parsed `Node::ArrayDecl` as an
+    /// `ArrayPattern`, rejecting a rest element anywhere but the last
+    /// position. `reinterpret` is applied to every non-elided element
+    /// (including the inner target of a rest element) so nested array/object
+    /// literals are themselves refined into patterns.
+    pub fn try_from_elements(
+        elements: Box<[Node]>,
+        reinterpret: impl Fn(Node) -> Result<Node, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let len = elements.len();
+        let mut bindings = Vec::with_capacity(len);
+
+        for (i, element) in elements.into_vec().into_iter().enumerate() {
+            let binding = match element {
+                Node::Empty => ArrayPatternElement::Elision,
+                Node::Spread(inner) => {
+                    if i + 1 != len {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "Rest element must be the last element in an array pattern".into(),
+                        )));
+                    }
+                    ArrayPatternElement::Rest(Box::new(reinterpret(*inner)?))
+                }
+                other => ArrayPatternElement::Pattern(reinterpret(other)?),
+            };
+            bindings.push(binding);
+        }
+
+        Ok(Self {
+            bindings: bindings.into_boxed_slice(),
+        })
+    }
+
+    /// Returns the pattern's bindings, in source order.
+    pub fn bindings(&self) -> &[ArrayPatternElement] {
+        &self.bindings
+    }
+}
+
+impl fmt::Display for ArrayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            match binding {
+                ArrayPatternElement::Elision => {}
+                ArrayPatternElement::Pattern(node) => write!(f, "{}", node)?,
+                ArrayPatternElement::Rest(node) => write!(f, "...{}", node)?,
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+impl From<ArrayPattern> for Node {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::ArrayPattern(pattern)
+    }
+}
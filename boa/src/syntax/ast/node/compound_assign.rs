@@ -0,0 +1,67 @@
+//! Compound assignment expression node.
+
+use super::Node;
+use crate::syntax::ast::op::AssignOp;
+use std::fmt;
+
+/// A compound or logical assignment expression: `target op= value`, e.g.
+/// `a += 1` or `a &&= b`.
+///
+/// Unlike a plain binary operation, the result is stored back into `target`
+/// rather than merely computed; the short-circuiting logical variants
+/// (`&&=`, `||=`, `??=`) additionally only evaluate and assign `value` when
+/// `target`'s current value is truthy, falsy, or nullish respectively, so
+/// they cannot be folded into a `BinOp` at parse time.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-assignment-operators
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompoundAssign {
+    op: AssignOp,
+    target: Box<Node>,
+    value: Box<Node>,
+}
+
+impl CompoundAssign {
+    /// Creates a new `CompoundAssign` node.
+    pub fn new<T, V>(op: AssignOp, target: T, value: V) -> Self
+    where
+        T: Into<Node>,
+        V: Into<Node>,
+    {
+        Self {
+            op,
+            target: Box::new(target.into()),
+            value: Box::new(value.into()),
+        }
+    }
+
+    /// Returns the assignment operator.
+    pub fn op(&self) -> AssignOp {
+        self.op
+    }
+
+    /// Returns the assignment target.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Returns the value being assigned.
+    pub fn value(&self) -> &Node {
+        &self.value
+    }
+}
+
+impl fmt::Display for CompoundAssign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.target, self.op, self.value)
+    }
+}
+
+impl From<CompoundAssign> for Node {
+    fn from(compound_assign: CompoundAssign) -> Self {
+        Self::CompoundAssign(compound_assign)
+    }
+}
@@ -0,0 +1,16 @@
+//! The AST node definitions used by the parser and interpreter.
+//!
+//! This module declares the node kinds introduced by the destructuring
+//! assignment and compound/logical assignment work; the rest of `Node`
+//! (`ArrayDecl`, `Object`, `Assign`, `BinOp`, `Yield`, `ArrowFunctionDecl`,
+//! etc.) lives alongside these and is unaffected by this change.
+
+mod array_pattern;
+mod compound_assign;
+mod object_pattern;
+
+pub use self::{
+    array_pattern::{ArrayPattern, ArrayPatternElement},
+    compound_assign::CompoundAssign,
+    object_pattern::{ObjectPattern, ObjectPatternElement},
+};
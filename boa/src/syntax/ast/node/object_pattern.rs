@@ -0,0 +1,112 @@
+//! Object destructuring assignment pattern node.
+
+use super::{
+    object::{PropertyDefinition, PropertyName},
+    Node,
+};
+use crate::syntax::{lexer::Error as LexError, parser::ParseError};
+use std::fmt;
+
+/// A single binding inside an `ObjectPattern`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectPatternElement {
+    /// A shorthand binding: `{a}` destructures property `a` into a binding
+    /// named `a`.
+    SingleName(Box<str>),
+    /// A renaming binding: `{a: b}` destructures property `a` into target
+    /// `b`, which may itself be a nested pattern and may carry a default.
+    KeyValue(PropertyName, Node),
+    /// A trailing rest element: `{...rest}`. Only valid in the final
+    /// position, and the target must be a plain binding (not a nested
+    /// pattern).
+    Rest(Box<Node>),
+}
+
+/// An object destructuring assignment target: `{a, b: c = 1, ...rest} = obj`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectPattern {
+    bindings: Box<[ObjectPatternElement]>,
+}
+
+impl ObjectPattern {
+    /// Reinterprets the properties of an already-parsed `Node::Object` as an
+    /// `ObjectPattern`, rejecting method definitions (not valid destructuring
+    /// targets), a rest element anywhere but the last position, and a rest
+    /// element whose target is an array or object literal.
+    /// `reinterpret` is applied to every non-rest value so a nested
+    /// array/object literal is itself refined into a pattern.
+    pub fn try_from_properties(
+        properties: Box<[PropertyDefinition]>,
+        reinterpret: impl Fn(Node) -> Result<Node, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let len = properties.len();
+        let mut bindings = Vec::with_capacity(len);
+
+        for (i, property) in properties.into_vec().into_iter().enumerate() {
+            let binding = match property {
+                PropertyDefinition::IdentifierReference(name) => {
+                    ObjectPatternElement::SingleName(name)
+                }
+                PropertyDefinition::Property(key, value) => {
+                    ObjectPatternElement::KeyValue(key, reinterpret(value)?)
+                }
+                PropertyDefinition::SpreadObject(target) => {
+                    if i + 1 != len {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "Rest element must be the last element in an object pattern".into(),
+                        )));
+                    }
+                    if let Node::ArrayDecl(_) | Node::Object(_) = target {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "Rest element of an object pattern must not be a nested pattern".into(),
+                        )));
+                    }
+                    ObjectPatternElement::Rest(Box::new(target))
+                }
+                PropertyDefinition::MethodDefinition(..) => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid destructuring assignment target".into(),
+                    )));
+                }
+            };
+            bindings.push(binding);
+        }
+
+        Ok(Self {
+            bindings: bindings.into_boxed_slice(),
+        })
+    }
+
+    /// Returns the pattern's bindings, in source order.
+    pub fn bindings(&self) -> &[ObjectPatternElement] {
+        &self.bindings
+    }
+}
+
+impl fmt::Display for ObjectPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            match binding {
+                ObjectPatternElement::SingleName(name) => write!(f, "{}", name)?,
+                ObjectPatternElement::KeyValue(key, node) => write!(f, "{}: {}", key, node)?,
+                ObjectPatternElement::Rest(node) => write!(f, "...{}", node)?,
+            }
+        }
+        f.write_str("}")
+    }
+}
+
+impl From<ObjectPattern> for Node {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::ObjectPattern(pattern)
+    }
+}
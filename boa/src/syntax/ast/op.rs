@@ -0,0 +1,76 @@
+//! Operator definitions used by the assignment operator parser.
+//!
+//! The numeric/relational/logical binary operator enum (returned by
+//! `Punctuator::as_binop`) lives alongside the rest of this module; only the
+//! assignment-specific operator set is defined here.
+
+use std::fmt;
+
+/// The operator of a compound or logical assignment expression, e.g. the
+/// `+=` in `a += b` or the `&&=` in `a &&= b`.
+///
+/// The logical variants (`BoolAnd`, `BoolOr`, `Coalesce`) are short-circuiting:
+/// the interpreter only evaluates and stores `value` when `target`'s current
+/// value is truthy, falsy, or nullish respectively, so they cannot be
+/// represented as a plain binary operation the way the arithmetic/bitwise
+/// variants can.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-assignment-operators
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssignOp {
+    /// `+=`
+    Add,
+    /// `-=`
+    Sub,
+    /// `*=`
+    Mul,
+    /// `/=`
+    Div,
+    /// `%=`
+    Mod,
+    /// `**=`
+    Exp,
+    /// `<<=`
+    Shl,
+    /// `>>=`
+    Shr,
+    /// `>>>=`
+    Ushr,
+    /// `&=`
+    And,
+    /// `|=`
+    Or,
+    /// `^=`
+    Xor,
+    /// `&&=`
+    BoolAnd,
+    /// `||=`
+    BoolOr,
+    /// `??=`
+    Coalesce,
+}
+
+impl fmt::Display for AssignOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Add => "+=",
+            Self::Sub => "-=",
+            Self::Mul => "*=",
+            Self::Div => "/=",
+            Self::Mod => "%=",
+            Self::Exp => "**=",
+            Self::Shl => "<<=",
+            Self::Shr => ">>=",
+            Self::Ushr => ">>>=",
+            Self::And => "&=",
+            Self::Or => "|=",
+            Self::Xor => "^=",
+            Self::BoolAnd => "&&=",
+            Self::BoolOr => "||=",
+            Self::Coalesce => "??=",
+        })
+    }
+}
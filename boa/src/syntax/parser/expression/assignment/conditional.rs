@@ -0,0 +1,189 @@
+//! Conditional (ternary) expression parsing, and the precedence-climbing
+//! core for everything below it.
+//!
+//! Boa used to thread binary-operator parsing through a fixed chain of
+//! structs, one per precedence level (`LogicalOrExpression` ->
+//! `LogicalAndExpression` -> ... -> `ExponentiationExpression`). That's
+//! replaced here by a single loop driven by an operator/precedence/fixity
+//! table, the way rustc's expression parser does with its `AssocOp` +
+//! `Fixity` pair: adding a new binary operator is now a one-line table
+//! entry instead of a new struct and a new link in the chain.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-ConditionalExpression
+
+use super::ExponentiationExpression;
+use crate::syntax::{
+    ast::{node::BinOp, op::CompOp, Keyword, Node, Punctuator},
+    lexer::TokenKind,
+    parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser},
+};
+use std::io::Read;
+
+/// Whether an operator groups with operands to its left or its right.
+///
+/// Left-associative: `a - b - c` is `(a - b) - c`, so the right operand is
+/// parsed with `min_prec = prec + 1` (it must bind *tighter* than this
+/// operator, or it would be swallowed by the next iteration of this loop
+/// instead). Right-associative: `a ** b ** c` is `a ** (b ** c)` and
+/// assignment chains similarly, so the right operand is parsed with
+/// `min_prec = prec` (an operator of the *same* precedence is allowed to
+/// nest on the right).
+///
+/// No entry of the [`precedence`] table is `Right`: `**` is parsed by
+/// [`ExponentiationExpression`] and assignment by `AssignmentExpression`,
+/// each through its own right-recursive call rather than this loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fixity {
+    Left,
+    Right,
+}
+
+/// Looks up a binary operator punctuator's precedence and fixity. Returns
+/// `None` for anything that isn't a binary operator (the loop in
+/// [`parse_binary_expression`] stops there).
+///
+/// Ordered lowest to highest precedence; `**` is handled separately by
+/// [`ExponentiationExpression`], one level above everything here.
+fn precedence(p: Punctuator) -> Option<(u8, Fixity)> {
+    use Punctuator::*;
+    Some(match p {
+        Coalesce => (1, Fixity::Left),
+        BoolOr => (2, Fixity::Left),
+        BoolAnd => (3, Fixity::Left),
+        Or => (4, Fixity::Left),
+        Xor => (5, Fixity::Left),
+        And => (6, Fixity::Left),
+        Eq | NotEq | StrictEq | StrictNotEq => (7, Fixity::Left),
+        LessThan | GreaterThan | LessThanOrEq | GreaterThanOrEq => (8, Fixity::Left),
+        Shl | Shr | UShr => (9, Fixity::Left),
+        Add | Sub => (10, Fixity::Left),
+        Mul | Div | Mod => (11, Fixity::Left),
+        _ => return None,
+    })
+}
+
+/// Parses a unary/`**` operand, then loops while the next token is a binary
+/// operator whose precedence is `>= min_prec`, folding each into a `BinOp`.
+///
+/// `allow_in` gates whether the `in` relational operator is recognized at
+/// all (it's excluded inside a `for (;;)` head's init clause, per spec).
+fn parse_binary_expression<R>(
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    cursor: &mut Cursor<R>,
+    min_prec: u8,
+) -> ParseResult
+where
+    R: Read,
+{
+    let mut lhs = ExponentiationExpression::new(allow_yield, allow_await).parse(cursor)?;
+
+    while let Some(tok) = cursor.peek(false)? {
+        // `in` and `instanceof` are lexed as keywords, not punctuators, so
+        // they can't go through the `Punctuator`-driven table below; they
+        // sit at the same precedence as the other relational operators.
+        let (prec, fixity, op) = match tok.kind() {
+            TokenKind::Keyword(Keyword::In) if allow_in.0 => (8, Fixity::Left, CompOp::In.into()),
+            TokenKind::Keyword(Keyword::InstanceOf) => {
+                (8, Fixity::Left, CompOp::InstanceOf.into())
+            }
+            TokenKind::Punctuator(p) => {
+                let (prec, fixity) = match precedence(*p) {
+                    Some(entry) => entry,
+                    None => break,
+                };
+                let op = p.as_binop().expect("operator disappeared between peek and consume");
+                (prec, fixity, op)
+            }
+            _ => break,
+        };
+
+        if prec < min_prec {
+            break;
+        }
+
+        cursor.next(false)?.expect("operator token vanished");
+        let next_min_prec = if fixity == Fixity::Left { prec + 1 } else { prec };
+        let rhs = parse_binary_expression(allow_in, allow_yield, allow_await, cursor, next_min_prec)?;
+        lhs = BinOp::new(op, lhs, rhs).into();
+    }
+
+    Ok(lhs)
+}
+
+/// Parses a conditional (ternary) expression: the binary-operator chain
+/// above, optionally followed by `? AssignmentExpression : AssignmentExpression`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ConditionalExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ConditionalExpression {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ConditionalExpression {
+    /// Creates a new `ConditionalExpression` parser.
+    pub(in crate::syntax::parser) fn new<I, Y, A>(
+        allow_in: I,
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ConditionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let cond = parse_binary_expression(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            cursor,
+            1,
+        )?;
+
+        if let Some(tok) = cursor.peek(false)? {
+            if tok.kind() == &TokenKind::Punctuator(Punctuator::Question) {
+                cursor.next(false)?.expect("? token vanished");
+                let then = super::AssignmentExpression::new(
+                    self.allow_in,
+                    self.allow_yield,
+                    self.allow_await,
+                )
+                .parse(cursor)?;
+                cursor.expect(Punctuator::Colon, "conditional expression")?;
+                let otherwise = super::AssignmentExpression::new(
+                    self.allow_in,
+                    self.allow_yield,
+                    self.allow_await,
+                )
+                .parse(cursor)?;
+                return Ok(Node::conditional_op(cond, then, otherwise));
+            }
+        }
+
+        Ok(cond)
+    }
+}
@@ -0,0 +1,84 @@
+//! Exponentiation expression parsing.
+//!
+//! This is the base operand of the precedence-climbing binary expression
+//! parser in [`conditional`](super::conditional): it parses a single unary
+//! expression, then folds in any right-associative `**` chain, since `**`
+//! is handled here rather than by the climbing loop (a unary expression is
+//! not a valid left operand of `**` per spec - `-2 ** 2` is a syntax error).
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-exp-operator
+
+use crate::syntax::{
+    ast::{node::BinOp, op::NumOp, Keyword, Node, Punctuator},
+    lexer::TokenKind,
+    parser::{
+        expression::unary::UnaryExpression, AllowAwait, AllowYield, Cursor, ParseResult,
+        TokenParser,
+    },
+};
+use std::io::Read;
+
+/// Parses an exponentiation expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExponentiationExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ExponentiationExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ExponentiationExpression {
+    /// Creates a new `ExponentiationExpression` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ExponentiationExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let unary_start = matches!(
+            cursor.peek(false)?.as_ref().map(|tok| tok.kind()),
+            Some(TokenKind::Keyword(Keyword::Delete))
+                | Some(TokenKind::Keyword(Keyword::Void))
+                | Some(TokenKind::Keyword(Keyword::TypeOf))
+                | Some(TokenKind::Punctuator(Punctuator::Add))
+                | Some(TokenKind::Punctuator(Punctuator::Sub))
+                | Some(TokenKind::Punctuator(Punctuator::Not))
+                | Some(TokenKind::Punctuator(Punctuator::Neg))
+        );
+        let lhs = UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        if unary_start {
+            return Ok(lhs);
+        }
+
+        if let Some(tok) = cursor.peek(false)? {
+            if tok.kind() == &crate::syntax::lexer::TokenKind::Punctuator(Punctuator::Exp) {
+                cursor.next(false)?.expect("** token vanished");
+                // Right-associative: the exponent itself may be another
+                // exponentiation expression (`2 ** 3 ** 2` is `2 ** (3 ** 2)`).
+                let rhs = Self::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                return Ok(BinOp::new(NumOp::Exp, lhs, rhs).into());
+            }
+        }
+
+        Ok(lhs)
+    }
+}
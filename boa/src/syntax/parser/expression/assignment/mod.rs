@@ -16,7 +16,8 @@ use crate::syntax::lexer::{Error as LexError, InputElement, Token, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
+            node::{ArrayPattern, Assign, CompoundAssign, Node, ObjectPattern, Yield},
+            op::AssignOp,
             Keyword, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
@@ -25,7 +26,9 @@ use crate::{
 };
 pub(super) use exponentiation::ExponentiationExpression;
 
+use std::cell::RefCell;
 use std::io::Read;
+use std::rc::Rc;
 
 /// Assignment expression parsing.
 ///
@@ -45,11 +48,27 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Assignment_Operators#Assignment
 /// [spec]: https://tc39.es/ecma262/#prod-AssignmentExpression
 /// [lhs]: ../lhs_expression/struct.LeftHandSideExpression.html
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(in crate::syntax::parser) struct AssignmentExpression {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    /// `Some` while parsing in recovering mode: every recursive
+    /// `AssignmentExpression::parse` call reached through *this* parser's own
+    /// `=`/compound-assign/`yield`-operand branches shares this sink, so a
+    /// failure nested arbitrarily deep in one of those chains (e.g. the
+    /// right-hand side of `a = b = c`) still reports into the same
+    /// top-level diagnostics list. `None` is the ordinary, bail-on-first-error
+    /// mode.
+    ///
+    /// This sink lives on `AssignmentExpression` itself, not on `Cursor`: a
+    /// sibling parser invoked from inside an operand (an object/array
+    /// literal element, a call argument, a nested statement, ...) builds its
+    /// own fresh `AssignmentExpression` with `errors: None` and cannot see
+    /// it, so a failure there still aborts the enclosing `parse_all` call
+    /// rather than being collected. Recovering across the whole grammar
+    /// would require the sink to live on `Cursor`/`TokenParser` instead.
+    errors: Option<Rc<RefCell<Vec<ParseError>>>>,
 }
 
 impl AssignmentExpression {
@@ -68,8 +87,133 @@ impl AssignmentExpression {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            errors: None,
         }
     }
+
+    /// Parses an assignment expression in recovering mode, collecting every
+    /// diagnostic reachable through this parser's own `=`/compound-assign/
+    /// `yield`-operand recursion instead of aborting on the first one.
+    ///
+    /// Recoverable failures - an invalid left-hand side, or a missing operand
+    /// after `=` - are pushed onto the shared diagnostics sink and replaced
+    /// by a synthetic [`Node::Error`], after which parsing resumes at the
+    /// next recovery point (a statement terminator, closing brace, line
+    /// terminator, or EOF).
+    ///
+    /// This does *not* yet recover across the whole grammar: a sibling parser
+    /// invoked from inside an operand (an object/array literal element, a
+    /// call argument, a nested statement, ...) builds its own fresh,
+    /// non-recovering `AssignmentExpression` and still aborts the whole
+    /// `parse_all` call on its first error. Getting IDE/linting consumers a
+    /// single pass over every error in a file requires moving this sink onto
+    /// `Cursor`/`TokenParser` so any sub-parser reachable during a parse can
+    /// report into it - this only covers the assignment-chain recursion
+    /// within a single `AssignmentExpression`.
+    ///
+    /// An error out of the *top-level* parse (e.g. an unrecoverable abrupt
+    /// end, or a failure before any recovery point was even reachable) is
+    /// still collected here rather than dropped, so the common case of a
+    /// syntax error in the very first operand is not silently swallowed.
+    pub(in crate::syntax::parser) fn parse_all<R>(
+        allow_in: impl Into<AllowIn>,
+        allow_yield: impl Into<AllowYield>,
+        allow_await: impl Into<AllowAwait>,
+        cursor: &mut Cursor<R>,
+    ) -> (Node, Vec<ParseError>)
+    where
+        R: Read,
+    {
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let mut parser = Self::new(allow_in, allow_yield, allow_await);
+        parser.errors = Some(Rc::clone(&errors));
+
+        let node = match parser.parse(cursor) {
+            Ok(node) => node,
+            Err(e) => {
+                errors.borrow_mut().push(e);
+                Node::Error
+            }
+        };
+
+        let errors = Rc::try_unwrap(errors)
+            .expect("no recursive parse should outlive its own top-level call")
+            .into_inner();
+        (node, errors)
+    }
+}
+
+/// Pushes `e` onto `sink`, if it is `Some`. A no-op otherwise.
+///
+/// Free function (rather than a method) so call sites can clone
+/// `AssignmentExpression::errors` out *before* a recursive `self.parse(...)`
+/// call moves `self`, and still report into the same sink afterwards.
+fn push_error(sink: &Option<Rc<RefCell<Vec<ParseError>>>>, e: ParseError) {
+    if let Some(sink) = sink {
+        sink.borrow_mut().push(e);
+    }
+}
+
+/// Looks past a `(` the cursor is sitting on for its matching `)`, then
+/// reports whether `=>` follows - without leaving any net effect on the
+/// cursor, so a caller that decides "no, this isn't an arrow function" can
+/// carry on as though it had never looked.
+///
+/// Used to disambiguate `async (a, b) => {}` from an ordinary call
+/// expression like `async(items, cb)`, where committing to `ArrowFunction`
+/// without this check would hard-fail on the missing `=>`.
+fn peek_is_arrow_after_params<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    let mut consumed = Vec::new();
+    let mut depth = 0i32;
+
+    while let Some(tok) = cursor.next(false)? {
+        let is_close = tok.kind() == &TokenKind::Punctuator(Punctuator::CloseParen);
+        let is_open = tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen);
+        consumed.push(tok);
+
+        if is_open {
+            depth += 1;
+        } else if is_close {
+            depth -= 1;
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+
+    let is_arrow = matches!(
+        cursor.peek(true)?,
+        Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow)
+    );
+
+    for tok in consumed.into_iter().rev() {
+        cursor.push_back(tok);
+    }
+
+    Ok(is_arrow)
+}
+
+/// Skips tokens until a recovery point - a statement terminator, closing
+/// brace, line terminator, or EOF - is reached (without consuming it), so a
+/// recovering-mode parse can resume at a sane boundary after a diagnostic.
+fn skip_to_recovery_point<R>(cursor: &mut Cursor<R>) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    while let Some(tok) = cursor.peek(false)? {
+        match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Semicolon)
+            | TokenKind::Punctuator(Punctuator::CloseBlock)
+            | TokenKind::LineTerminator => break,
+            _ => {
+                cursor.next(false)?;
+            }
+        }
+    }
+    Ok(())
 }
 
 impl<R> TokenParser<R> for AssignmentExpression
@@ -82,6 +226,15 @@ where
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::Div);
 
+        // YieldExpression
+        if self.allow_yield.0 {
+            if let Some(tok) = cursor.peek(false)? {
+                if tok.kind() == &TokenKind::Keyword(Keyword::Yield) {
+                    return self.parse_yield(cursor);
+                }
+            }
+        }
+
         // Arrow function
         match cursor.peek(true)?.ok_or(ParseError::AbruptEnd)?.kind() {
             // a=>{}
@@ -123,6 +276,52 @@ where
                 }
             }
 
+            // async x => {} / async (a, b) => {}
+            //
+            // `async` is a contextual keyword, also valid as a plain
+            // identifier (`async(items, cb)` is an ordinary call expression,
+            // a real pattern predating `async`/`await`). So unlike the two
+            // arms above, spotting an identifier or `(` right after `async`
+            // isn't enough to commit: we additionally confirm `=>` actually
+            // follows before treating it as an async arrow function, putting
+            // back every token we looked at otherwise so the normal
+            // expression parse below sees an untouched cursor.
+            TokenKind::Keyword(Keyword::Async) if cursor.peek_expect_no_lineterminator(true).is_ok() => {
+                if let Some(next_token) = cursor.peek_skip(false)? {
+                    let is_async_arrow = match next_token.kind() {
+                        TokenKind::Identifier(_)
+                        | TokenKind::Keyword(Keyword::Yield)
+                        | TokenKind::Keyword(Keyword::Await) => {
+                            let async_tok = cursor.next(true)?.expect("async keyword vanished");
+                            let confirmed = cursor.peek_expect_no_lineterminator(true).is_ok()
+                                && matches!(
+                                    cursor.peek_skip(false)?,
+                                    Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow)
+                                );
+                            if !confirmed {
+                                cursor.push_back(async_tok);
+                            }
+                            confirmed
+                        }
+                        TokenKind::Punctuator(Punctuator::OpenParen) => {
+                            let async_tok = cursor.next(true)?.expect("async keyword vanished");
+                            let confirmed = peek_is_arrow_after_params(cursor)?;
+                            if !confirmed {
+                                cursor.push_back(async_tok);
+                            }
+                            confirmed
+                        }
+                        _ => false,
+                    };
+
+                    if is_async_arrow {
+                        return ArrowFunction::new(self.allow_in, self.allow_yield, true)
+                            .parse(cursor)
+                            .map(Node::AsyncArrowFunctionDecl);
+                    }
+                }
+            }
+
             _ => {}
         }
 
@@ -138,21 +337,52 @@ where
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next(false)?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
-                        lhs = Assign::new(lhs, self.parse(cursor)?).into();
-                        break;
-                    } else {
-                        return Err(ParseError::lex(LexError::Syntax(
-                            "Invalid left-hand side in assignment".into(),
-                        )));
+                    // Clone the sink (and not just a `bool`) before `self` is
+                    // moved into the recursive `self.parse(cursor)` call below.
+                    let errors = self.errors.clone();
+                    match reinterpret_as_assignment_target(lhs) {
+                        Ok(target) => match self.parse(cursor) {
+                            Ok(rhs) => lhs = Assign::new(target, rhs).into(),
+                            Err(e) if errors.is_some() => {
+                                push_error(&errors, e);
+                                skip_to_recovery_point(cursor)?;
+                                lhs = Node::Error;
+                            }
+                            Err(e) => return Err(e),
+                        },
+                        Err(e) if errors.is_some() => {
+                            push_error(&errors, e);
+                            skip_to_recovery_point(cursor)?;
+                            lhs = Node::Error;
+                        }
+                        Err(e) => return Err(e),
                     }
+                    break;
                 }
-                TokenKind::Punctuator(p) if p.as_binop().is_some() => {
-                    cursor.next(false)?.expect("Token vanished"); // Consume the token.
+                TokenKind::Punctuator(p) if assign_op(*p).is_some() => {
+                    let op = assign_op(*p).expect("assign op disappeared");
+                    cursor.next(false)?.expect("Token vanished"); // Consume the operator.
+                    let errors = self.errors.clone();
                     if is_assignable(&lhs) {
-                        let expr = self.parse(cursor)?;
-                        let binop = p.as_binop().expect("binop disappeared");
-                        lhs = BinOp::new(binop, lhs, expr).into();
+                        match self.parse(cursor) {
+                            Ok(rhs) => lhs = CompoundAssign::new(op, lhs, rhs).into(),
+                            Err(e) if errors.is_some() => {
+                                push_error(&errors, e);
+                                skip_to_recovery_point(cursor)?;
+                                lhs = Node::Error;
+                            }
+                            Err(e) => return Err(e),
+                        }
+                        break;
+                    } else if errors.is_some() {
+                        push_error(
+                            &errors,
+                            ParseError::lex(LexError::Syntax(
+                                "Invalid left-hand side in assignment".into(),
+                            )),
+                        );
+                        skip_to_recovery_point(cursor)?;
+                        lhs = Node::Error;
                         break;
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
@@ -176,14 +406,379 @@ where
     }
 }
 
+impl AssignmentExpression {
+    /// Parses a `YieldExpression`: `yield`, `yield* AssignmentExpression` or
+    /// `yield AssignmentExpression`.
+    ///
+    /// Only reachable when `self.allow_yield` is set, i.e. while parsing a
+    /// generator function body. Per spec there must be no line terminator
+    /// between `yield` and a following `*` or operand, so a line terminator
+    /// token right after `yield` simply means the expression has no operand.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+    fn parse_yield<R>(self, cursor: &mut Cursor<R>) -> ParseResult
+    where
+        R: Read,
+    {
+        cursor.next(false)?.expect("yield keyword vanished"); // Consume `yield`.
+
+        let delegate = match cursor.peek(false)? {
+            Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next(false)?.expect("* token vanished");
+                true
+            }
+            _ => false,
+        };
+
+        let expr = match cursor.peek(false)? {
+            Some(tok)
+                if !matches!(
+                    tok.kind(),
+                    TokenKind::Punctuator(Punctuator::Semicolon)
+                        | TokenKind::Punctuator(Punctuator::CloseBlock)
+                        | TokenKind::Punctuator(Punctuator::CloseParen)
+                        | TokenKind::Punctuator(Punctuator::CloseBracket)
+                        | TokenKind::Punctuator(Punctuator::Comma)
+                        | TokenKind::LineTerminator
+                ) =>
+            {
+                // Reuse `self` rather than building a fresh parser: the flags
+                // are identical, and this preserves the recovering-mode
+                // diagnostics sink into the yielded operand. Clone the sink
+                // (not just a `bool`) before `self` is moved into the
+                // recursive `self.parse(cursor)` call below, matching the
+                // `=`/compound-assign branches in `parse`.
+                let errors = self.errors.clone();
+                match self.parse(cursor) {
+                    Ok(operand) => Some(Box::new(operand)),
+                    Err(e) if errors.is_some() => {
+                        push_error(&errors, e);
+                        skip_to_recovery_point(cursor)?;
+                        Some(Box::new(Node::Error))
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            _ => None,
+        };
+
+        Ok(Yield::new(expr, delegate).into())
+    }
+}
+
 /// Returns true if as per spec[spec] the node can be assigned a value.
 ///
+/// Destructuring targets (`Node::ArrayDecl`, `Node::Object`) are rejected
+/// here too: they are only valid left-hand sides for a plain `=`, refined
+/// via [`reinterpret_as_assignment_target`], never for a compound/logical
+/// assignment.
+///
 /// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
 #[inline]
 pub(crate) fn is_assignable(node: &Node) -> bool {
-    if let Node::Const(_) | Node::ArrayDecl(_) = node {
+    if let Node::Const(_) | Node::ArrayDecl(_) | Node::Object(_) = node {
         false
     } else {
         true
     }
 }
+
+/// Maps a compound/logical assignment punctuator (`+=`, `&&=`, `??=`, ...) to
+/// its `AssignOp`, or `None` if `p` is not one.
+///
+/// `a += b` assigns into `a`, it does not merely evaluate to `a + b`, so it
+/// needs its own [`CompoundAssign`] node rather than being folded into a
+/// `BinOp` the way the (now-removed) `Punctuator::as_binop` branch used to
+/// do for these punctuators.
+#[inline]
+fn assign_op(p: Punctuator) -> Option<AssignOp> {
+    match p {
+        Punctuator::AssignAdd => Some(AssignOp::Add),
+        Punctuator::AssignSub => Some(AssignOp::Sub),
+        Punctuator::AssignMul => Some(AssignOp::Mul),
+        Punctuator::AssignDiv => Some(AssignOp::Div),
+        Punctuator::AssignMod => Some(AssignOp::Mod),
+        Punctuator::AssignPow => Some(AssignOp::Exp),
+        Punctuator::AssignShl => Some(AssignOp::Shl),
+        Punctuator::AssignShr => Some(AssignOp::Shr),
+        Punctuator::AssignUShr => Some(AssignOp::Ushr),
+        Punctuator::AssignAnd => Some(AssignOp::And),
+        Punctuator::AssignOr => Some(AssignOp::Or),
+        Punctuator::AssignXor => Some(AssignOp::Xor),
+        // Short-circuiting logical assignments: the right-hand side is only
+        // evaluated (and assigned) when the left operand is truthy/falsy/
+        // nullish respectively - the interpreter implements that, this layer
+        // only needs to recognize and shape the node.
+        Punctuator::AssignBoolAnd => Some(AssignOp::BoolAnd),
+        Punctuator::AssignBoolOr => Some(AssignOp::BoolOr),
+        Punctuator::AssignCoalesce => Some(AssignOp::Coalesce),
+        _ => None,
+    }
+}
+
+/// Refines `node` into a valid `AssignmentExpression` left-hand side, per the
+/// spec's `AssignmentPattern` cover grammar.
+///
+/// `ConditionalExpression` has no way to know ahead of time whether an array
+/// or object literal it is parsing will turn out to be a destructuring
+/// assignment target once a `=` is reached, so it always produces a plain
+/// `Node::ArrayDecl`/`Node::Object`. Once the `=` is seen, this reinterprets
+/// that literal as an `ArrayPattern`/`ObjectPattern` (keeping holes as
+/// elisions, rejecting rest elements in non-final position, method
+/// definitions and non-assignable targets along the way), leaving any other
+/// already-assignable node untouched.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+fn reinterpret_as_assignment_target(node: Node) -> Result<Node, ParseError> {
+    match node {
+        Node::ArrayDecl(elements) => Ok(ArrayPattern::try_from_elements(
+            elements,
+            reinterpret_as_assignment_target,
+        )?
+        .into()),
+        Node::Object(properties) => Ok(ObjectPattern::try_from_properties(
+            properties,
+            reinterpret_as_assignment_target,
+        )?
+        .into()),
+        node if is_assignable(&node) => Ok(node),
+        _ => Err(ParseError::lex(LexError::Syntax(
+            "Invalid left-hand side in assignment".into(),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::ast::{
+        node::BinOp,
+        op::{self, CompOp, NumOp},
+    };
+
+    fn parse(src: &str, allow_yield: bool) -> ParseResult {
+        AssignmentExpression::new(true, allow_yield, false).parse(&mut Cursor::new(src.as_bytes()))
+    }
+
+    #[test]
+    fn non_recovering_mode_bails_on_invalid_lhs() {
+        assert!(parse("1 = 2", false).is_err());
+    }
+
+    #[test]
+    fn recovering_mode_collects_invalid_lhs_instead_of_aborting() {
+        let (node, errors) =
+            AssignmentExpression::parse_all(true, false, false, &mut Cursor::new(b"1 = 2" as &[u8]));
+        assert!(matches!(node, Node::Error));
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn array_pattern_rejects_a_rest_element_that_is_not_last() {
+        let elements: Box<[Node]> = vec![Node::Spread(Box::new(Node::Empty)), Node::Empty].into();
+        assert!(ArrayPattern::try_from_elements(elements, reinterpret_as_assignment_target).is_err());
+    }
+
+    #[test]
+    fn array_pattern_accepts_elision_and_trailing_rest() {
+        let elements: Box<[Node]> = vec![Node::Empty, Node::Spread(Box::new(Node::Empty))].into();
+        let pattern = ArrayPattern::try_from_elements(elements, reinterpret_as_assignment_target)
+            .expect("a trailing rest element should be accepted");
+        assert!(matches!(pattern.bindings()[0], ArrayPatternElement::Elision));
+        assert!(matches!(pattern.bindings()[1], ArrayPatternElement::Rest(_)));
+    }
+
+    #[test]
+    fn object_pattern_rejects_a_rest_element_that_is_not_last() {
+        use crate::syntax::ast::node::object::PropertyDefinition;
+        let properties: Box<[PropertyDefinition]> = vec![
+            PropertyDefinition::SpreadObject(Node::Empty),
+            PropertyDefinition::IdentifierReference("a".into()),
+        ]
+        .into();
+        assert!(
+            ObjectPattern::try_from_properties(properties, reinterpret_as_assignment_target).is_err()
+        );
+    }
+
+    #[test]
+    fn object_pattern_accepts_shorthand_and_trailing_rest() {
+        use crate::syntax::ast::node::object::PropertyDefinition;
+        let properties: Box<[PropertyDefinition]> = vec![
+            PropertyDefinition::IdentifierReference("a".into()),
+            PropertyDefinition::SpreadObject(Node::Empty),
+        ]
+        .into();
+        let pattern = ObjectPattern::try_from_properties(properties, reinterpret_as_assignment_target)
+            .expect("a trailing rest element should be accepted");
+        assert!(matches!(
+            pattern.bindings()[0],
+            ObjectPatternElement::SingleName(_)
+        ));
+        assert!(matches!(pattern.bindings()[1], ObjectPatternElement::Rest(_)));
+    }
+
+    #[test]
+    fn object_pattern_renaming_retains_the_source_key() {
+        use crate::syntax::ast::node::object::{PropertyDefinition, PropertyName};
+        let properties: Box<[PropertyDefinition]> = vec![PropertyDefinition::Property(
+            PropertyName::Literal("a".into()),
+            Node::Empty,
+        )]
+        .into();
+        let pattern = ObjectPattern::try_from_properties(properties, reinterpret_as_assignment_target)
+            .expect("a renaming property should be accepted");
+        match &pattern.bindings()[0] {
+            ObjectPatternElement::KeyValue(key, value) => {
+                assert_eq!(key.to_string(), "a");
+                assert!(matches!(value, Node::Empty));
+            }
+            other => panic!("expected a KeyValue binding, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn yield_with_no_operand() {
+        let node = parse("yield", true).expect("parse");
+        assert!(matches!(node, Node::Yield(_)));
+    }
+
+    #[test]
+    fn yield_delegate_with_operand() {
+        let node = parse("yield* gen()", true).expect("parse");
+        assert!(matches!(node, Node::Yield(_)));
+    }
+
+    #[test]
+    fn yield_operand_error_recovers_instead_of_aborting() {
+        let (node, errors) = AssignmentExpression::parse_all(
+            false,
+            true,
+            false,
+            &mut Cursor::new(b"yield +;" as &[u8]),
+        );
+        assert!(matches!(node, Node::Yield(_)));
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn assign_op_maps_every_compound_and_logical_punctuator() {
+        assert_eq!(assign_op(Punctuator::AssignAdd), Some(AssignOp::Add));
+        assert_eq!(assign_op(Punctuator::AssignBoolAnd), Some(AssignOp::BoolAnd));
+        assert_eq!(assign_op(Punctuator::AssignBoolOr), Some(AssignOp::BoolOr));
+        assert_eq!(assign_op(Punctuator::AssignCoalesce), Some(AssignOp::Coalesce));
+        assert_eq!(assign_op(Punctuator::Assign), None);
+    }
+
+    #[test]
+    fn is_assignable_rejects_array_and_object_literals() {
+        assert!(!is_assignable(&Node::ArrayDecl(Box::new([]))));
+        assert!(!is_assignable(&Node::Object(Box::new([]))));
+    }
+
+    #[test]
+    fn compound_assign_builds_the_expected_node() {
+        let node: Node = CompoundAssign::new(AssignOp::BoolAnd, Node::Empty, Node::Empty).into();
+        assert!(matches!(node, Node::CompoundAssign(_)));
+    }
+
+    #[test]
+    fn async_arrow_with_single_identifier_param() {
+        let node = parse("async x => x", false).expect("parse");
+        assert!(matches!(node, Node::AsyncArrowFunctionDecl(_)));
+    }
+
+    #[test]
+    fn async_arrow_with_parenthesized_params() {
+        let node = parse("async (a, b) => a", false).expect("parse");
+        assert!(matches!(node, Node::AsyncArrowFunctionDecl(_)));
+    }
+
+    #[test]
+    fn async_followed_by_line_terminator_is_not_an_arrow() {
+        // A line terminator right after `async` rules out the arrow-function
+        // reading; if this still parses, it must not have gone that way.
+        if let Ok(node) = parse("async\n(x)", false) {
+            assert!(!matches!(node, Node::AsyncArrowFunctionDecl(_)));
+        }
+    }
+
+    #[test]
+    fn bare_async_call_is_not_mistaken_for_an_arrow() {
+        let node = parse("async(items, cb)", false).expect("parse");
+        assert!(!matches!(node, Node::AsyncArrowFunctionDecl(_)));
+    }
+
+    #[test]
+    fn async_arrow_with_yield_or_await_as_param() {
+        assert!(matches!(
+            parse("async yield => yield", false).expect("parse"),
+            Node::AsyncArrowFunctionDecl(_)
+        ));
+        assert!(matches!(
+            parse("async await => x", false).expect("parse"),
+            Node::AsyncArrowFunctionDecl(_)
+        ));
+    }
+
+    fn as_binop(node: &Node) -> &BinOp {
+        match node {
+            Node::BinOp(bin_op) => bin_op,
+            other => panic!("expected a BinOp node, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn left_associative_operators_nest_on_the_left() {
+        // `1 - 2 - 3` is `(1 - 2) - 3`: the left child is itself a `BinOp`,
+        // the right child is the plain literal `3`.
+        let node = parse("1 - 2 - 3", false).expect("parse");
+        let outer = as_binop(&node);
+        assert_eq!(outer.op(), op::BinOp::Num(NumOp::Sub));
+        let inner = as_binop(outer.lhs());
+        assert_eq!(inner.op(), op::BinOp::Num(NumOp::Sub));
+        assert!(!matches!(outer.rhs(), Node::BinOp(_)));
+    }
+
+    #[test]
+    fn right_associative_operators_nest_on_the_right() {
+        // `2 ** 3 ** 2` is `2 ** (3 ** 2)`: the right child is itself a
+        // `BinOp`, the left child is the plain literal `2`.
+        let node = parse("2 ** 3 ** 2", false).expect("parse");
+        let outer = as_binop(&node);
+        assert_eq!(outer.op(), op::BinOp::Num(NumOp::Exp));
+        assert!(!matches!(outer.lhs(), Node::BinOp(_)));
+        let inner = as_binop(outer.rhs());
+        assert_eq!(inner.op(), op::BinOp::Num(NumOp::Exp));
+    }
+
+    #[test]
+    fn higher_precedence_operators_fold_first() {
+        // `1 + 2 * 3` is `1 + (2 * 3)`: the `*` binds tighter, so it ends up
+        // nested under the `+` as the right operand.
+        let node = parse("1 + 2 * 3", false).expect("parse");
+        let outer = as_binop(&node);
+        assert_eq!(outer.op(), op::BinOp::Num(NumOp::Add));
+        assert!(!matches!(outer.lhs(), Node::BinOp(_)));
+        let inner = as_binop(outer.rhs());
+        assert_eq!(inner.op(), op::BinOp::Num(NumOp::Mul));
+    }
+
+    #[test]
+    fn allow_in_gates_the_in_relational_operator() {
+        let allowed = ConditionalExpression::new(true, false, false)
+            .parse(&mut Cursor::new(b"a in b" as &[u8]))
+            .expect("parse");
+        assert_eq!(as_binop(&allowed).op(), op::BinOp::Comp(CompOp::In));
+
+        // With `in` disallowed (e.g. inside a `for (;;)` head), the climbing
+        // loop must stop before consuming it, leaving just the identifier.
+        let disallowed = ConditionalExpression::new(false, false, false)
+            .parse(&mut Cursor::new(b"a in b" as &[u8]))
+            .expect("parse");
+        assert!(!matches!(disallowed, Node::BinOp(_)));
+    }
+}